@@ -25,8 +25,10 @@ use futures::TryStreamExt;
 use reqwest::{self, IntoUrl, Url};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::time::Duration;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use thiserror::Error;
+use tokio::sync::Mutex;
 use tokio_util::codec::{FramedRead, LinesCodec};
 use tokio_util::io::StreamReader;
 
@@ -73,7 +75,8 @@ impl From<Format> for String {
 /// Main client for interacting with Ollama API
 #[derive(Clone)]
 pub struct Ollama {
-    /// API endpoint URL
+    /// Base URL of the Ollama server (e.g. `http://localhost:11434`); any
+    /// path component is ignored when resolving API endpoints
     pub url: Url,
     /// Model name to use for generation
     pub model: String,
@@ -83,10 +86,61 @@ pub struct Ollama {
     pub context: Vec<u64>,
     /// System prompt for model instructions
     pub system: String,
+    /// Role-tagged message history for the chat API
+    pub history: Vec<ChatMessage>,
+    /// Headers (e.g. bearer token) attached to every request
+    pub headers: reqwest::header::HeaderMap,
+    /// Maximum number of requests to dispatch per second, if throttled
+    pub max_requests_per_second: Option<f32>,
+    /// Timestamp of the last dispatched request, shared across clones
+    rate_limiter: Arc<Mutex<Instant>>,
+    /// Sampling and context-window parameters applied to every request
+    pub generation_options: OllamaGenerationOptions,
+    /// Tools the model may call during a chat
+    pub tools: Vec<Tool>,
+}
+
+/// Sampling and context-window parameters sent as the request's nested `options` object
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OllamaGenerationOptions {
+    /// Context window size, in tokens (Ollama reports no max-token info, so this defaults to 4096)
+    pub num_ctx: u32,
+    /// Sampling temperature
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    /// Nucleus sampling threshold
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    /// Top-k sampling cutoff
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    /// Random seed for deterministic output
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
+    /// Penalty applied to repeated tokens
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_penalty: Option<f32>,
+    /// Sequences that stop generation when encountered
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+}
+
+impl Default for OllamaGenerationOptions {
+    fn default() -> Self {
+        Self {
+            num_ctx: 4096,
+            temperature: None,
+            top_p: None,
+            top_k: None,
+            seed: None,
+            repeat_penalty: None,
+            stop: None,
+        }
+    }
 }
 
 /// Configuration options for generation requests
-#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct OllamaRequestOptions {
     /// Suffix to append to the generated response
     pub suffix: String,
@@ -96,10 +150,12 @@ pub struct OllamaRequestOptions {
     pub system: String,
     /// Conversation context tokens
     pub context: Vec<u64>,
+    /// Sampling and context-window parameters
+    pub generation_options: OllamaGenerationOptions,
 }
 
 /// Complete request structure for generation
-#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub struct OllamaRequest {
     /// Model identifier
     pub model: String,
@@ -117,6 +173,8 @@ pub struct OllamaRequest {
     pub raw: bool,
     /// Context tokens for conversation history
     pub context: Vec<u64>,
+    /// Sampling and context-window parameters
+    pub options: OllamaGenerationOptions,
 }
 
 impl OllamaRequest {
@@ -144,6 +202,7 @@ impl OllamaRequest {
             stream,
             raw,
             context: options.context,
+            options: options.generation_options,
         }
     }
 }
@@ -159,6 +218,7 @@ impl Default for OllamaRequest {
                 format: Format::None,
                 system: "".into(),
                 context: vec![],
+                generation_options: OllamaGenerationOptions::default(),
             },
             false,
             false,
@@ -230,6 +290,178 @@ impl TryFrom<String> for OllamaResponse {
     }
 }
 
+/// Role of a participant in a chat conversation
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// System instructions shown to the model
+    System,
+    /// Messages authored by the end user
+    User,
+    /// Messages authored by the model
+    Assistant,
+    /// Results returned from executing a tool call
+    Tool,
+}
+
+/// A single role-tagged message in a chat conversation
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ChatMessage {
+    /// Who is speaking in this message
+    pub role: Role,
+    /// Message text
+    pub content: String,
+    /// Tool calls requested by the model, if any
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tool_calls: Vec<ToolCall>,
+}
+
+impl ChatMessage {
+    /// Create a new chat message
+    ///
+    /// # Arguments
+    /// * `role` - Who is speaking in this message
+    /// * `content` - Message text
+    pub fn new(role: Role, content: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: content.into(),
+            tool_calls: vec![],
+        }
+    }
+}
+
+/// JSON-schema definition of a function a tool exposes
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FunctionDefinition {
+    /// Function name the model refers to when calling it
+    pub name: String,
+    /// Human-readable description of what the function does
+    pub description: String,
+    /// JSON schema describing the function's parameters
+    pub parameters: Value,
+}
+
+/// A callable tool the model may invoke during a chat
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct Tool {
+    /// Tool kind; Ollama currently only defines `"function"`
+    #[serde(rename = "type")]
+    pub tool_type: String,
+    /// The function definition for this tool
+    pub function: FunctionDefinition,
+}
+
+impl Tool {
+    /// Wrap a function definition as a `"function"`-typed tool
+    pub fn function(function: FunctionDefinition) -> Self {
+        Self {
+            tool_type: "function".into(),
+            function,
+        }
+    }
+}
+
+/// A function invocation requested by the model
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ToolCallFunction {
+    /// Name of the function to call
+    pub name: String,
+    /// Arguments to call the function with
+    pub arguments: Value,
+}
+
+/// A single tool call parsed from an assistant message
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ToolCall {
+    /// The requested function invocation
+    pub function: ToolCallFunction,
+}
+
+/// Request body for the `/api/chat` endpoint
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OllamaChatRequest {
+    /// Model identifier
+    pub model: String,
+    /// Full conversation history sent to the model
+    pub messages: Vec<ChatMessage>,
+    /// Stream response flag
+    pub stream: bool,
+    /// Sampling and context-window parameters
+    pub options: OllamaGenerationOptions,
+    /// Tools the model may call during this chat
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tools: Vec<Tool>,
+}
+
+/// Complete response from a chat request
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct OllamaChatResponse {
+    /// Model identifier
+    pub model: String,
+    /// Timestamp of response creation
+    pub created_at: String,
+    /// Assistant reply message
+    pub message: ChatMessage,
+    /// Completion status flag
+    pub done: bool,
+    /// Completion reason if finished
+    #[serde(default)]
+    pub done_reason: Option<String>,
+    /// Total request duration in nanoseconds
+    #[serde(default)]
+    pub total_duration: Option<u64>,
+    /// Model loading duration in nanoseconds
+    #[serde(default)]
+    pub load_duration: Option<u64>,
+    /// Number of tokens in prompt evaluation
+    #[serde(default)]
+    pub prompt_eval_count: Option<u32>,
+    /// Prompt evaluation duration in nanoseconds
+    #[serde(default)]
+    pub prompt_eval_duration: Option<u64>,
+    /// Number of tokens generated
+    #[serde(default)]
+    pub eval_count: Option<u16>,
+    /// Generation duration in nanoseconds
+    #[serde(default)]
+    pub eval_duration: Option<u64>,
+}
+
+/// Streaming response chunk for the `/api/chat` endpoint
+#[derive(Serialize, Deserialize, Debug)]
+pub struct OllamaChatStreamResponse {
+    /// Model identifier
+    pub model: String,
+    /// Timestamp of response creation
+    pub created_at: String,
+    /// Delta message for this chunk
+    pub message: ChatMessage,
+    /// Completion status flag
+    pub done: bool,
+    /// Completion reason if finished
+    #[serde(default)]
+    pub done_reason: Option<String>,
+    /// Total duration metrics
+    #[serde(default)]
+    pub total_duration: Option<u64>,
+    /// Model loading duration
+    #[serde(default)]
+    pub load_duration: Option<u64>,
+    /// Prompt evaluation metrics
+    #[serde(default)]
+    pub prompt_eval_count: Option<u32>,
+    /// Prompt evaluation duration
+    #[serde(default)]
+    pub prompt_eval_duration: Option<u64>,
+    /// Generation metrics
+    #[serde(default)]
+    pub eval_count: Option<u16>,
+    /// Generation duration
+    #[serde(default)]
+    pub eval_duration: Option<u64>,
+}
+
 /// Streaming response chunk
 #[derive(Serialize, Deserialize, Debug)]
 pub struct OllamaStreamResponse {
@@ -267,6 +499,69 @@ pub struct OllamaStreamResponse {
     pub eval_duration: Option<u64>,
 }
 
+/// Quantization and architecture details for an installed model
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct OllamaModelDetails {
+    /// Parent model this one was derived from, if any
+    #[serde(default)]
+    pub parent_model: String,
+    /// On-disk model format, e.g. `gguf`
+    #[serde(default)]
+    pub format: String,
+    /// Model family, e.g. `llama`
+    #[serde(default)]
+    pub family: String,
+    /// All model families this model belongs to
+    #[serde(default)]
+    pub families: Option<Vec<String>>,
+    /// Parameter count, e.g. `7B`
+    #[serde(default)]
+    pub parameter_size: String,
+    /// Quantization level, e.g. `Q4_0`
+    #[serde(default)]
+    pub quantization_level: String,
+}
+
+/// Metadata for a single model installed on the server
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct OllamaModelInfo {
+    /// Model name, e.g. `llama3.2:latest`
+    pub name: String,
+    /// Timestamp the model was last modified
+    pub modified_at: String,
+    /// Size of the model on disk, in bytes
+    pub size: u64,
+    /// Content digest of the model
+    #[serde(default)]
+    pub digest: String,
+    /// Architecture and quantization details
+    #[serde(default)]
+    pub details: OllamaModelDetails,
+}
+
+/// Response body for the `/api/tags` endpoint
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct OllamaTagsResponse {
+    /// Models installed on the server
+    pub models: Vec<OllamaModelInfo>,
+}
+
+/// Request body for the `/api/embeddings` endpoint
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OllamaEmbeddingsRequest {
+    /// Model identifier
+    pub model: String,
+    /// Input text to embed
+    pub prompt: String,
+}
+
+/// Response body for the `/api/embeddings` endpoint
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OllamaEmbeddingsResponse {
+    /// Embedding vector for the input prompt
+    pub embedding: Vec<f32>,
+}
+
 impl Ollama {
     /// Create a new Ollama client instance
     ///
@@ -292,9 +587,145 @@ impl Ollama {
             client,
             context: vec![],
             system: "".into(),
+            history: vec![],
+            headers: reqwest::header::HeaderMap::new(),
+            max_requests_per_second: None,
+            rate_limiter: Arc::new(Mutex::new(
+                Instant::now()
+                    .checked_sub(Duration::from_secs(3600))
+                    .unwrap_or_else(Instant::now),
+            )),
+            generation_options: OllamaGenerationOptions::default(),
+            tools: vec![],
         })
     }
 
+    /// Register tools the model may call during a chat
+    pub fn with_tools(mut self, tools: Vec<Tool>) -> Self {
+        self.tools = tools;
+        self
+    }
+
+    /// Set the context window size, in tokens
+    pub fn with_num_ctx(mut self, num_ctx: u32) -> Self {
+        self.generation_options.num_ctx = num_ctx;
+        self
+    }
+
+    /// Set the sampling temperature
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.generation_options.temperature = Some(temperature);
+        self
+    }
+
+    /// Set the nucleus sampling threshold
+    pub fn with_top_p(mut self, top_p: f32) -> Self {
+        self.generation_options.top_p = Some(top_p);
+        self
+    }
+
+    /// Set the top-k sampling cutoff
+    pub fn with_top_k(mut self, top_k: u32) -> Self {
+        self.generation_options.top_k = Some(top_k);
+        self
+    }
+
+    /// Set the random seed used for deterministic output
+    pub fn with_seed(mut self, seed: i64) -> Self {
+        self.generation_options.seed = Some(seed);
+        self
+    }
+
+    /// Set the penalty applied to repeated tokens
+    pub fn with_repeat_penalty(mut self, repeat_penalty: f32) -> Self {
+        self.generation_options.repeat_penalty = Some(repeat_penalty);
+        self
+    }
+
+    /// Set the sequences that stop generation when encountered
+    pub fn with_stop(mut self, stop: Vec<String>) -> Self {
+        self.generation_options.stop = Some(stop);
+        self
+    }
+
+    /// Cap outgoing requests to at most `max_requests_per_second`
+    ///
+    /// The limiter state is shared across clones of this `Ollama`, so all
+    /// clones throttle against the same budget. Only `generate`,
+    /// `stream_generate`, `chat`, `continue_chat`, `stream_chat` and
+    /// `embed`/`embed_many` are throttled; `generate_blocking`,
+    /// `list_models` and `is_available` are not. A rate that is zero,
+    /// negative or not finite disables throttling.
+    pub fn with_rate_limit(mut self, max_requests_per_second: f32) -> Self {
+        self.max_requests_per_second = Some(max_requests_per_second);
+        self
+    }
+
+    /// Wait, if necessary, until `max_requests_per_second` permits dispatch
+    async fn throttle(&self) {
+        let Some(rate) = self.max_requests_per_second else {
+            return;
+        };
+        if !rate.is_finite() || rate <= 0.0 {
+            return;
+        }
+        let min_interval = Duration::from_secs_f32(1.0 / rate);
+
+        let mut last_dispatch = self.rate_limiter.lock().await;
+        let elapsed = last_dispatch.elapsed();
+        if elapsed < min_interval {
+            tokio::time::sleep(min_interval - elapsed).await;
+        }
+        *last_dispatch = Instant::now();
+    }
+
+    /// Attach a bearer token to every subsequent request
+    ///
+    /// # Arguments
+    /// * `token` - Token value sent as `Authorization: Bearer <token>`
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Result<Self, OllamaError> {
+        let value = format!("Bearer {}", token.into());
+        self.headers.insert(
+            reqwest::header::AUTHORIZATION,
+            value.parse().map_err(|e: reqwest::header::InvalidHeaderValue| {
+                OllamaError::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e))
+            })?,
+        );
+        Ok(self)
+    }
+
+    /// Attach an arbitrary header to every subsequent request
+    ///
+    /// # Arguments
+    /// * `name` - Header name
+    /// * `value` - Header value
+    pub fn with_header(
+        mut self,
+        name: impl AsRef<str>,
+        value: impl AsRef<str>,
+    ) -> Result<Self, OllamaError> {
+        let header_name = reqwest::header::HeaderName::from_bytes(name.as_ref().as_bytes())
+            .map_err(|e| OllamaError::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
+        let header_value = reqwest::header::HeaderValue::from_str(value.as_ref())
+            .map_err(|e| OllamaError::Io(std::io::Error::new(std::io::ErrorKind::InvalidInput, e)))?;
+        self.headers.insert(header_name, header_value);
+        Ok(self)
+    }
+
+    /// Resolve an Ollama API endpoint on the configured server
+    ///
+    /// Ignores any path already present on `self.url` and rebuilds
+    /// `/api/<path>` against its scheme/host/port, so it agrees with
+    /// both a bare `self.url` (e.g. `http://localhost:11434`, as built
+    /// by `create_default`/`new`) and one that already carries a path.
+    fn endpoint(&self, path: &str) -> Result<Url, OllamaError> {
+        let mut url = self.url.clone();
+        url.set_path(&format!("/api/{path}"));
+        url.set_query(None);
+        url.set_fragment(None);
+        Ok(url)
+    }
+
     /// Create a client with default settings
     ///
     /// Defaults to localhost:11434 and llama3.2 model
@@ -312,6 +743,8 @@ impl Ollama {
         &mut self,
         prompt: impl Into<String>,
     ) -> Result<OllamaResponse, OllamaError> {
+        self.throttle().await;
+
         let request = OllamaRequest::new(
             self.model.as_str(),
             prompt,
@@ -320,6 +753,7 @@ impl Ollama {
                 format: Format::None,
                 system: self.system.clone(),
                 context: self.context.clone(),
+                generation_options: self.generation_options.clone(),
             },
             false,
             false,
@@ -328,7 +762,8 @@ impl Ollama {
         let request_json = serde_json::to_string(&request)?;
         let res = self
             .client
-            .post(self.url.as_str())
+            .post(self.endpoint("generate")?)
+            .headers(self.headers.clone())
             .body(request_json)
             .send()
             .await?;
@@ -372,6 +807,8 @@ impl Ollama {
         &mut self,
         prompt: impl Into<String>,
     ) -> Result<impl Stream<Item = Result<OllamaStreamResponse, OllamaError>>, OllamaError> {
+        self.throttle().await;
+
         let request = OllamaRequest::new(
             self.model.as_str(),
             prompt,
@@ -380,6 +817,7 @@ impl Ollama {
                 format: Format::None,
                 system: self.system.clone(),
                 context: self.context.clone(),
+                generation_options: self.generation_options.clone(),
             },
             true,
             false,
@@ -388,7 +826,8 @@ impl Ollama {
         let request_json = serde_json::to_string(&request)?;
         let res = self
             .client
-            .post(self.url.as_str())
+            .post(self.endpoint("generate")?)
+            .headers(self.headers.clone())
             .body(request_json)
             .send()
             .await?;
@@ -425,6 +864,7 @@ impl Ollama {
                 format: Format::None,
                 system: self.system.clone(),
                 context: self.context.clone(),
+                generation_options: self.generation_options.clone(),
             },
             false,
             false,
@@ -433,7 +873,8 @@ impl Ollama {
         let request_json = serde_json::to_string(&request)?;
 
         let response_text = reqwest::blocking::Client::new()
-            .post(self.url.as_str())
+            .post(self.endpoint("generate")?)
+            .headers(self.headers.clone())
             .body(request_json)
             .timeout(Duration::from_secs_f64(300.0))
             .send()?
@@ -443,4 +884,200 @@ impl Ollama {
         self.context = response.context.clone();
         Ok(response)
     }
+
+    /// Dispatch a non-streaming `/api/chat` request for the given messages
+    async fn dispatch_chat(&self, messages: Vec<ChatMessage>) -> Result<ChatMessage, OllamaError> {
+        let request = OllamaChatRequest {
+            model: self.model.clone(),
+            messages,
+            stream: false,
+            options: self.generation_options.clone(),
+            tools: self.tools.clone(),
+        };
+
+        let request_json = serde_json::to_string(&request)?;
+        let res = self
+            .client
+            .post(self.endpoint("chat")?)
+            .headers(self.headers.clone())
+            .body(request_json)
+            .send()
+            .await?
+            .error_for_status()?;
+        let res_text = res.text().await?;
+        let response: OllamaChatResponse = serde_json::from_str(&res_text)?;
+        Ok(response.message)
+    }
+
+    /// Send a user message and get the assistant's reply
+    ///
+    /// Appends a user message to `self.history`, sends the full message
+    /// history to `/api/chat`, then appends the assistant's reply to
+    /// `self.history` and returns it.
+    ///
+    /// # Arguments
+    /// * `prompt` - Input text prompt
+    pub async fn chat(
+        &mut self,
+        prompt: impl Into<String>,
+    ) -> Result<ChatMessage, OllamaError> {
+        self.throttle().await;
+
+        let user_message = ChatMessage::new(Role::User, prompt);
+        let mut messages = self.history.clone();
+        messages.push(user_message.clone());
+
+        let reply = self.dispatch_chat(messages).await?;
+
+        self.history.push(user_message);
+        self.history.push(reply.clone());
+        Ok(reply)
+    }
+
+    /// Continue a chat turn using `self.history` as-is
+    ///
+    /// Unlike `chat`, this does not append a new user message. Use it
+    /// after executing a function the model requested (an assistant
+    /// reply's `tool_calls`) and pushing a `role: "tool"` result message
+    /// onto `self.history`, to get the model's follow-up turn without
+    /// injecting a synthetic user message into the transcript.
+    pub async fn continue_chat(&mut self) -> Result<ChatMessage, OllamaError> {
+        self.throttle().await;
+
+        let reply = self.dispatch_chat(self.history.clone()).await?;
+
+        self.history.push(reply.clone());
+        Ok(reply)
+    }
+
+    /// Stream the assistant's reply to a user message in real-time
+    ///
+    /// Appends a user message to `self.history` and returns a stream of
+    /// per-chunk deltas, analogous to `stream_generate`. Unlike `chat`,
+    /// the streamed deltas are not automatically appended to `self.history`.
+    pub async fn stream_chat(
+        &mut self,
+        prompt: impl Into<String>,
+    ) -> Result<impl Stream<Item = Result<OllamaChatStreamResponse, OllamaError>>, OllamaError>
+    {
+        self.throttle().await;
+
+        let user_message = ChatMessage::new(Role::User, prompt);
+        let mut messages = self.history.clone();
+        messages.push(user_message.clone());
+
+        let request = OllamaChatRequest {
+            model: self.model.clone(),
+            messages,
+            stream: true,
+            options: self.generation_options.clone(),
+            tools: self.tools.clone(),
+        };
+
+        let request_json = serde_json::to_string(&request)?;
+        let res = self
+            .client
+            .post(self.endpoint("chat")?)
+            .headers(self.headers.clone())
+            .body(request_json)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        self.history.push(user_message);
+
+        let byte_stream = res
+            .bytes_stream()
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e));
+        let stream_reader = StreamReader::new(byte_stream);
+
+        let lines = FramedRead::new(stream_reader, LinesCodec::new());
+
+        let parsed = lines.filter_map(|line_result| async move {
+            match line_result {
+                Ok(line) if !line.trim().is_empty() => Some(
+                    serde_json::from_str::<OllamaChatStreamResponse>(&line).map_err(Into::into),
+                ),
+                Ok(_) => None,
+                Err(e) => Some(Err(e.into())),
+            }
+        });
+
+        Ok(parsed)
+    }
+
+    /// List the models currently installed on the server
+    ///
+    /// GETs `/api/tags` (resolved against the server root, regardless of
+    /// any path on `self.url`) and returns parsed model metadata (name,
+    /// size, modified time, parameter details).
+    pub async fn list_models(&self) -> Result<Vec<OllamaModelInfo>, OllamaError> {
+        let res = self
+            .client
+            .get(self.endpoint("tags")?)
+            .headers(self.headers.clone())
+            .send()
+            .await?
+            .error_for_status()?;
+        let tags: OllamaTagsResponse = res.json().await?;
+        Ok(tags.models)
+    }
+
+    /// Check that the Ollama server is reachable
+    ///
+    /// GETs `/api/tags` (resolved against the server root, regardless of
+    /// any path on `self.url`) and returns `Ok(())` if the server
+    /// responds and an error otherwise, so callers can fail fast instead
+    /// of hitting a generic reqwest error mid-generation.
+    pub async fn is_available(&self) -> Result<(), OllamaError> {
+        self.client
+            .get(self.endpoint("tags")?)
+            .headers(self.headers.clone())
+            .send()
+            .await?
+            .error_for_status()?;
+        Ok(())
+    }
+
+    /// Compute an embedding vector for a single input
+    ///
+    /// POSTs to `/api/embeddings` (resolved against the server root,
+    /// regardless of any path on `self.url`) using the client's
+    /// configured `model`.
+    pub async fn embed(&self, input: impl Into<String>) -> Result<Vec<f32>, OllamaError> {
+        self.throttle().await;
+
+        let request = OllamaEmbeddingsRequest {
+            model: self.model.clone(),
+            prompt: input.into(),
+        };
+
+        let request_json = serde_json::to_string(&request)?;
+        let res = self
+            .client
+            .post(self.endpoint("embeddings")?)
+            .headers(self.headers.clone())
+            .body(request_json)
+            .send()
+            .await?
+            .error_for_status()?;
+        let parsed: OllamaEmbeddingsResponse = res.json().await?;
+        Ok(parsed.embedding)
+    }
+
+    /// Compute embedding vectors for a batch of inputs
+    ///
+    /// `/api/embeddings` only accepts a single prompt per request, so this
+    /// dispatches one `embed` call per input and collects the results in
+    /// order.
+    pub async fn embed_many(
+        &self,
+        inputs: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Result<Vec<Vec<f32>>, OllamaError> {
+        let mut embeddings = Vec::new();
+        for input in inputs {
+            embeddings.push(self.embed(input).await?);
+        }
+        Ok(embeddings)
+    }
 }